@@ -0,0 +1,211 @@
+//! HTTP/JSON surface for the execution engine, documented with an OpenAPI 3 schema.
+//!
+//! This is the "real frontend" for `is_safe_entry` / `validate_order`: any client
+//! that can speak JSON over HTTP can drive the risk engine without going through
+//! the Python (pyo3) or WASM bridges. The order book is shared, server-wide
+//! state, so `/entry/check` reflects the spread live orders actually produced.
+
+use std::sync::{Arc, Mutex};
+
+use axum::{extract::Json, extract::State, http::StatusCode, routing::get, routing::post, Router};
+use serde::{Deserialize, Serialize};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::config::RiskLimits;
+use crate::orderbook::{OrderBook, Trade};
+use crate::sim::{run_monte_carlo, SimulationInput, SimulationOutput};
+use crate::{validate_order, Order};
+
+#[derive(Clone)]
+struct AppState {
+    limits: Arc<RiskLimits>,
+    book: Arc<Mutex<OrderBook>>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct HealthResponse {
+    status: &'static str,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct OrderValidateRequest {
+    pub order: Order,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct OrderValidateResponse {
+    pub valid: bool,
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct EntryCheckRequest {
+    pub current_price: f64,
+    pub volatility: f64,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct EntryCheckResponse {
+    pub safe: bool,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct SubmitResponse {
+    pub accepted: bool,
+    pub reason: String,
+    pub trades: Vec<Trade>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CancelRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct CancelResponse {
+    pub cancelled: bool,
+}
+
+#[utoipa::path(get, path = "/health", responses((status = 200, body = HealthResponse)))]
+async fn health() -> Json<HealthResponse> {
+    Json(HealthResponse { status: "ok" })
+}
+
+#[utoipa::path(
+    post,
+    path = "/orders/validate",
+    request_body = OrderValidateRequest,
+    responses((status = 200, body = OrderValidateResponse))
+)]
+async fn orders_validate(
+    State(state): State<AppState>,
+    Json(req): Json<OrderValidateRequest>,
+) -> Json<OrderValidateResponse> {
+    let limits = &state.limits.order;
+    let (valid, reason) = validate_order(
+        req.order.price,
+        req.order.amount,
+        limits.min_amount,
+        limits.max_amount,
+        limits.balance,
+    );
+    Json(OrderValidateResponse { valid, reason })
+}
+
+#[utoipa::path(
+    post,
+    path = "/orders/submit",
+    request_body = Order,
+    responses((status = 200, body = SubmitResponse))
+)]
+async fn orders_submit(State(state): State<AppState>, Json(order): Json<Order>) -> Json<SubmitResponse> {
+    let mut book = state.book.lock().expect("order book mutex poisoned");
+    match book.submit(order, &state.limits.order) {
+        Ok(trades) => Json(SubmitResponse {
+            accepted: true,
+            reason: "Valid".to_string(),
+            trades,
+        }),
+        Err(reason) => Json(SubmitResponse {
+            accepted: false,
+            reason,
+            trades: Vec::new(),
+        }),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/orders/cancel",
+    request_body = CancelRequest,
+    responses((status = 200, body = CancelResponse))
+)]
+async fn orders_cancel(State(state): State<AppState>, Json(req): Json<CancelRequest>) -> Json<CancelResponse> {
+    let cancelled = state.book.lock().expect("order book mutex poisoned").cancel(&req.id);
+    Json(CancelResponse { cancelled })
+}
+
+#[utoipa::path(
+    post,
+    path = "/entry/check",
+    request_body = EntryCheckRequest,
+    responses((status = 200, body = EntryCheckResponse))
+)]
+async fn entry_check(
+    State(state): State<AppState>,
+    Json(req): Json<EntryCheckRequest>,
+) -> Json<EntryCheckResponse> {
+    let limits = &state.limits.entry;
+    let safe = state
+        .book
+        .lock()
+        .expect("order book mutex poisoned")
+        .check_entry(req.current_price, req.volatility, limits.max_spread, limits.max_volatility);
+    Json(EntryCheckResponse { safe })
+}
+
+#[utoipa::path(
+    post,
+    path = "/simulate",
+    request_body = SimulationInput,
+    responses(
+        (status = 200, body = SimulationOutput),
+        (status = 400, description = "invalid simulation input")
+    )
+)]
+async fn simulate(Json(req): Json<SimulationInput>) -> Result<Json<SimulationOutput>, (StatusCode, String)> {
+    run_monte_carlo(&req).map(Json).map_err(|err| (StatusCode::BAD_REQUEST, err))
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(health, orders_validate, orders_submit, orders_cancel, entry_check, simulate),
+    components(schemas(
+        HealthResponse,
+        Order,
+        OrderValidateRequest,
+        OrderValidateResponse,
+        SubmitResponse,
+        CancelRequest,
+        CancelResponse,
+        Trade,
+        EntryCheckRequest,
+        EntryCheckResponse,
+        SimulationInput,
+        SimulationOutput
+    )),
+    tags((name = "execution-engine", description = "Order validation, matching, entry checks and simulation"))
+)]
+struct ApiDoc;
+
+/// Builds the full axum router: risk endpoints, the order book, health check
+/// and Swagger UI serving the generated OpenAPI document. `limits` is the
+/// `serve`-wide [`crate::config::RiskLimits`] (see that module for why it's
+/// split into substructs); the order book is shared across all requests so
+/// `/entry/check` sees the spread live orders actually produce.
+pub fn router(limits: RiskLimits) -> Router {
+    let state = AppState {
+        limits: Arc::new(limits),
+        book: Arc::new(Mutex::new(OrderBook::new())),
+    };
+
+    Router::new()
+        .route("/health", get(health))
+        .route("/orders/validate", post(orders_validate))
+        .route("/orders/submit", post(orders_submit))
+        .route("/orders/cancel", post(orders_cancel))
+        .route("/entry/check", post(entry_check))
+        .route("/simulate", post(simulate))
+        .merge(SwaggerUi::new("/docs").url("/openapi.json", ApiDoc::openapi()))
+        .with_state(state)
+}
+
+/// Starts the HTTP server on `port` and blocks until it shuts down.
+pub async fn serve(port: u16, limits: RiskLimits) {
+    let app = router(limits);
+    let addr = std::net::SocketAddr::from(([0, 0, 0, 0], port));
+    println!("Execution Engine API listening on {}", addr);
+    let listener = tokio::net::TcpListener::bind(addr).await.expect("failed to bind address");
+    axum::serve(listener, app).await.expect("server error");
+}
@@ -0,0 +1,150 @@
+//! `clap`-driven launcher for the execution engine binary, replacing the old
+//! hardcoded heartbeat loop. Each subcommand flattens only the
+//! [`crate::config`] substruct its logic actually reads (see that module for
+//! why the limits are split the way they are).
+
+use std::io::Read;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use serde::de::DeserializeOwned;
+
+use crate::config::{EntryLimits, OrderLimits, RiskLimits};
+use crate::sim::{run_monte_carlo, SimulationInput};
+use crate::{is_safe_entry, validate_order, Order};
+
+#[derive(Debug, Parser)]
+#[command(name = "execution-engine", about = "Execution engine service and CLI")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Start the axum HTTP/JSON API.
+    Serve {
+        /// Port to listen on.
+        #[arg(long, env = "PORT", default_value_t = 8080)]
+        port: u16,
+        #[command(flatten)]
+        limits: RiskLimits,
+    },
+    /// Validate a single order, from flags or a JSON `Order` on stdin.
+    Validate {
+        #[arg(long)]
+        price: Option<f64>,
+        #[arg(long)]
+        amount: Option<f64>,
+        #[command(flatten)]
+        limits: OrderLimits,
+    },
+    /// Check whether a live entry is safe to take, from flags or JSON on stdin.
+    Check {
+        #[arg(long)]
+        current_price: Option<f64>,
+        #[arg(long)]
+        spread: Option<f64>,
+        #[arg(long)]
+        volatility: Option<f64>,
+        #[command(flatten)]
+        limits: EntryLimits,
+    },
+    /// Run the Monte Carlo VaR simulation and print the resulting risk metrics.
+    Simulate {
+        /// JSON simulation input; reads stdin if omitted.
+        #[arg(long)]
+        input: Option<String>,
+    },
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct EntryCheckInput {
+    current_price: f64,
+    spread: f64,
+    volatility: f64,
+}
+
+fn read_stdin() -> Result<String, String> {
+    let mut buf = String::new();
+    std::io::stdin()
+        .read_to_string(&mut buf)
+        .map_err(|err| format!("failed to read stdin: {err}"))?;
+    Ok(buf)
+}
+
+fn parse_json<T: DeserializeOwned>(raw: &str, what: &str) -> Result<T, String> {
+    serde_json::from_str(raw).map_err(|err| format!("invalid {what} JSON: {err}"))
+}
+
+fn cmd_validate(limits: &OrderLimits, price: Option<f64>, amount: Option<f64>) -> Result<(), String> {
+    let (price, amount) = match (price, amount) {
+        (Some(price), Some(amount)) => (price, amount),
+        _ => {
+            let order: Order = parse_json(&read_stdin()?, "order")?;
+            (order.price, order.amount)
+        }
+    };
+    let (valid, reason) = validate_order(price, amount, limits.min_amount, limits.max_amount, limits.balance);
+    println!("{}", serde_json::json!({ "valid": valid, "reason": reason }));
+    Ok(())
+}
+
+fn cmd_check(
+    limits: &EntryLimits,
+    current_price: Option<f64>,
+    spread: Option<f64>,
+    volatility: Option<f64>,
+) -> Result<(), String> {
+    let (current_price, spread, volatility) = match (current_price, spread, volatility) {
+        (Some(current_price), Some(spread), Some(volatility)) => (current_price, spread, volatility),
+        _ => {
+            let input: EntryCheckInput = parse_json(&read_stdin()?, "entry check")?;
+            (input.current_price, input.spread, input.volatility)
+        }
+    };
+    let safe = is_safe_entry(current_price, spread, volatility, limits.max_spread, limits.max_volatility);
+    println!("{}", serde_json::json!({ "safe": safe }));
+    Ok(())
+}
+
+fn cmd_simulate(input: Option<String>) -> Result<(), String> {
+    let raw = match input {
+        Some(raw) => raw,
+        None => read_stdin()?,
+    };
+    let input: SimulationInput = parse_json(&raw, "simulation input")?;
+    let output = run_monte_carlo(&input)?;
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&output).map_err(|err| format!("failed to serialize output: {err}"))?
+    );
+    Ok(())
+}
+
+/// Runs the subcommand selected on `cli`, printing an error and exiting
+/// non-zero on malformed input instead of panicking with a raw backtrace.
+pub async fn run(cli: Cli) -> ExitCode {
+    let result = match cli.command {
+        Command::Serve { port, limits } => {
+            crate::api::serve(port, limits).await;
+            Ok(())
+        }
+        Command::Validate { price, amount, limits } => cmd_validate(&limits, price, amount),
+        Command::Check {
+            current_price,
+            spread,
+            volatility,
+            limits,
+        } => cmd_check(&limits, current_price, spread, volatility),
+        Command::Simulate { input } => cmd_simulate(input),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
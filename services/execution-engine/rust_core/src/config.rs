@@ -0,0 +1,43 @@
+//! Runtime risk limits, shared by the HTTP API and the CLI.
+//!
+//! These used to be passed ad hoc into `validate_order` / `is_safe_entry` at
+//! every call site. Centralizing them here means an operator sets them once,
+//! as CLI flags or environment variables, and every caller sees the same
+//! thresholds. [`EntryLimits`] and [`OrderLimits`] are split so a CLI
+//! subcommand only has to supply the flags the logic it runs actually reads;
+//! [`RiskLimits`] composes both for contexts like `serve` that run every
+//! route.
+
+use clap::Args;
+
+#[derive(Debug, Clone, Args)]
+pub struct EntryLimits {
+    /// Maximum acceptable bid-ask spread for an entry to be considered safe.
+    #[arg(long, env = "MAX_SPREAD", default_value_t = 1.0)]
+    pub max_spread: f64,
+    /// Maximum acceptable volatility for an entry to be considered safe.
+    #[arg(long, env = "MAX_VOLATILITY", default_value_t = 1.0)]
+    pub max_volatility: f64,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct OrderLimits {
+    /// Minimum order amount accepted by `validate_order`.
+    #[arg(long, env = "MIN_AMOUNT", default_value_t = 0.0)]
+    pub min_amount: f64,
+    /// Maximum order amount accepted by `validate_order`.
+    #[arg(long, env = "MAX_AMOUNT", default_value_t = 1_000_000.0)]
+    pub max_amount: f64,
+    /// Account balance used to reject orders the caller can't afford. There's
+    /// no sane default for this one, so it must be set explicitly.
+    #[arg(long, env = "BALANCE")]
+    pub balance: f64,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct RiskLimits {
+    #[command(flatten)]
+    pub entry: EntryLimits,
+    #[command(flatten)]
+    pub order: OrderLimits,
+}
@@ -5,20 +5,25 @@ use wasm_bindgen::prelude::*;
 #[cfg(feature = "python-backend")]
 use pyo3::prelude::*;
 
-#[derive(Serialize, Deserialize, Debug)]
-struct Order {
-    id: String,
-    price: f64,
-    amount: f64,
-    side: String,
+pub mod api;
+pub mod cli;
+pub mod config;
+pub mod orderbook;
+pub mod sampling;
+pub mod sim;
+
+#[derive(Serialize, Deserialize, Debug, Clone, utoipa::ToSchema)]
+pub struct Order {
+    pub id: String,
+    pub price: f64,
+    pub amount: f64,
+    pub side: String,
 }
 
-// --- Python Section (Backend Only) ---
-// এই অংশ শুধু Python এর জন্য কম্পাইল হবে
+// --- Core risk logic (shared by the HTTP API, the CLI and the Python bridge) ---
 
-#[cfg(feature = "python-backend")]
-#[pyfunction]
-fn is_safe_entry(current_price: f64, spread: f64, volatility: f64, max_spread: f64, max_volatility: f64) -> bool {
+pub fn is_safe_entry(current_price: f64, spread: f64, volatility: f64, max_spread: f64, max_volatility: f64) -> bool {
+    let _ = current_price;
     if spread > max_spread {
         return false;
     }
@@ -28,9 +33,7 @@ fn is_safe_entry(current_price: f64, spread: f64, volatility: f64, max_spread: f
     true
 }
 
-#[cfg(feature = "python-backend")]
-#[pyfunction]
-fn validate_order(price: f64, amount: f64, min_amount: f64, max_amount: f64, balance: f64) -> (bool, String) {
+pub fn validate_order(price: f64, amount: f64, min_amount: f64, max_amount: f64, balance: f64) -> (bool, String) {
     if amount < min_amount {
         return (false, "Amount below minimum limit".to_string());
     }
@@ -46,11 +49,26 @@ fn validate_order(price: f64, amount: f64, min_amount: f64, max_amount: f64, bal
     (true, "Valid".to_string())
 }
 
+// --- Python Section (Backend Only) ---
+// এই অংশ শুধু Python এর জন্য কম্পাইল হবে
+
+#[cfg(feature = "python-backend")]
+#[pyfunction(name = "is_safe_entry")]
+fn py_is_safe_entry(current_price: f64, spread: f64, volatility: f64, max_spread: f64, max_volatility: f64) -> bool {
+    is_safe_entry(current_price, spread, volatility, max_spread, max_volatility)
+}
+
+#[cfg(feature = "python-backend")]
+#[pyfunction(name = "validate_order")]
+fn py_validate_order(price: f64, amount: f64, min_amount: f64, max_amount: f64, balance: f64) -> (bool, String) {
+    validate_order(price, amount, min_amount, max_amount, balance)
+}
+
 #[cfg(feature = "python-backend")]
 #[pymodule]
 fn rust_core(_py: Python, m: &PyModule) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(is_safe_entry, m)?)?;
-    m.add_function(wrap_pyfunction!(validate_order, m)?)?;
+    m.add_function(wrap_pyfunction!(py_is_safe_entry, m)?)?;
+    m.add_function(wrap_pyfunction!(py_validate_order, m)?)?;
     Ok(())
 }
 
@@ -59,14 +77,14 @@ fn rust_core(_py: Python, m: &PyModule) -> PyResult<()> {
 
 #[wasm_bindgen]
 pub fn run_heavy_sim(data: &str) -> String {
-    // Simulate a heavy Monte Carlo simulation
-    let iterations = 1_000_000;
-    let mut score = 0.0;
-    
-    // Simple CPU burn loop to simulate "work"
-    for i in 0..iterations {
-        score += (i as f64).sqrt().sin();
-    }
-    
-    format!("Simulation Complete. Processed {} iterations. Score: {:.4}. Data received: {}", iterations, score, data)
+    let input: sim::SimulationInput = match serde_json::from_str(data) {
+        Ok(input) => input,
+        Err(err) => return format!("{{\"error\":\"invalid simulation input: {}\"}}", err),
+    };
+
+    let output = match sim::run_monte_carlo(&input) {
+        Ok(output) => output,
+        Err(err) => return format!("{{\"error\":\"{}\"}}", err),
+    };
+    serde_json::to_string(&output).unwrap_or_else(|err| format!("{{\"error\":\"{}\"}}", err))
 }
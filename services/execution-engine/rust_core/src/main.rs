@@ -1,13 +1,10 @@
-use std::thread;
-use std::time::Duration;
+use std::process::ExitCode;
 
-fn main() {
-    println!("Execution Engine (rust_core) Service Started.");
-    println!("Running in loop to keep container alive...");
-    
-    // Infinite loop to keep the service running
-    loop {
-        thread::sleep(Duration::from_secs(60));
-        println!("Heartbeat: Execution Engine is alive.");
-    }
+use clap::Parser;
+use rust_core::cli::Cli;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    rust_core::cli::run(cli).await
 }
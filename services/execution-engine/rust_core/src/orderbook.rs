@@ -0,0 +1,331 @@
+//! Price-time-priority order matching engine built around [`crate::Order`].
+//!
+//! Each side of the book is a `BTreeMap` keyed by price, bids sorted
+//! descending and asks ascending, with a FIFO queue at each price level so
+//! orders at the same price fill in the order they arrived.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, VecDeque};
+
+use serde::Serialize;
+
+use crate::config::OrderLimits;
+use crate::Order;
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct Trade {
+    pub maker_id: String,
+    pub taker_id: String,
+    pub price: f64,
+    pub amount: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    fn parse(raw: &str) -> Option<Side> {
+        match raw.to_ascii_lowercase().as_str() {
+            "buy" | "bid" => Some(Side::Buy),
+            "sell" | "ask" => Some(Side::Sell),
+            _ => None,
+        }
+    }
+}
+
+/// Wraps `f64` so prices can be used as `BTreeMap` keys. `OrderBook::submit`
+/// rejects NaN prices before they reach the book, so `Ord` falls back to
+/// `Equal` only in that unreachable case.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PriceKey(f64);
+
+impl Eq for PriceKey {}
+
+impl Ord for PriceKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(std::cmp::Ordering::Equal)
+    }
+}
+
+impl PartialOrd for PriceKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct OrderBook {
+    bids: BTreeMap<Reverse<PriceKey>, VecDeque<Order>>,
+    asks: BTreeMap<PriceKey, VecDeque<Order>>,
+}
+
+impl OrderBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates `order` against `limits` and, if it passes, crosses it
+    /// against the opposite side of the book, generating partial or full
+    /// fills at the resting order's price, then rests any remaining amount
+    /// on this order's own side. Rejects the order (without touching the
+    /// book) if it fails `validate_order`, has a NaN price, or has a side
+    /// other than buy/bid or sell/ask.
+    pub fn submit(&mut self, mut order: Order, limits: &OrderLimits) -> Result<Vec<Trade>, String> {
+        let (valid, reason) = crate::validate_order(
+            order.price,
+            order.amount,
+            limits.min_amount,
+            limits.max_amount,
+            limits.balance,
+        );
+        if !valid {
+            return Err(reason);
+        }
+        if order.price.is_nan() {
+            return Err("Price must be a number".to_string());
+        }
+        let Some(side) = Side::parse(&order.side) else {
+            return Err(format!("Unknown side: {}", order.side));
+        };
+
+        let mut trades = Vec::new();
+
+        match side {
+            Side::Buy => {
+                while order.amount > 0.0 {
+                    let Some((&best_price, _)) = self.asks.iter().next() else {
+                        break;
+                    };
+                    if order.price < best_price.0 {
+                        break;
+                    }
+                    Self::fill_from(&mut self.asks, best_price, &mut order, &mut trades);
+                }
+                if order.amount > 0.0 {
+                    self.bids
+                        .entry(Reverse(PriceKey(order.price)))
+                        .or_default()
+                        .push_back(order);
+                }
+            }
+            Side::Sell => {
+                while order.amount > 0.0 {
+                    let Some((&Reverse(best_price), _)) = self.bids.iter().next() else {
+                        break;
+                    };
+                    if order.price > best_price.0 {
+                        break;
+                    }
+                    Self::fill_from_bids(&mut self.bids, best_price, &mut order, &mut trades);
+                }
+                if order.amount > 0.0 {
+                    self.asks
+                        .entry(PriceKey(order.price))
+                        .or_default()
+                        .push_back(order);
+                }
+            }
+        }
+
+        Ok(trades)
+    }
+
+    fn fill_from(
+        book: &mut BTreeMap<PriceKey, VecDeque<Order>>,
+        price: PriceKey,
+        taker: &mut Order,
+        trades: &mut Vec<Trade>,
+    ) {
+        let queue = book.get_mut(&price).expect("price level just looked up");
+        while taker.amount > 0.0 {
+            let Some(mut resting) = queue.pop_front() else {
+                break;
+            };
+            let fill_amount = taker.amount.min(resting.amount);
+            trades.push(Trade {
+                maker_id: resting.id.clone(),
+                taker_id: taker.id.clone(),
+                price: resting.price,
+                amount: fill_amount,
+            });
+            taker.amount -= fill_amount;
+            resting.amount -= fill_amount;
+            if resting.amount > 0.0 {
+                queue.push_front(resting);
+                break;
+            }
+        }
+        if queue.is_empty() {
+            book.remove(&price);
+        }
+    }
+
+    fn fill_from_bids(
+        book: &mut BTreeMap<Reverse<PriceKey>, VecDeque<Order>>,
+        price: PriceKey,
+        taker: &mut Order,
+        trades: &mut Vec<Trade>,
+    ) {
+        let key = Reverse(price);
+        let queue = book.get_mut(&key).expect("price level just looked up");
+        while taker.amount > 0.0 {
+            let Some(mut resting) = queue.pop_front() else {
+                break;
+            };
+            let fill_amount = taker.amount.min(resting.amount);
+            trades.push(Trade {
+                maker_id: resting.id.clone(),
+                taker_id: taker.id.clone(),
+                price: resting.price,
+                amount: fill_amount,
+            });
+            taker.amount -= fill_amount;
+            resting.amount -= fill_amount;
+            if resting.amount > 0.0 {
+                queue.push_front(resting);
+                break;
+            }
+        }
+        if queue.is_empty() {
+            book.remove(&key);
+        }
+    }
+
+    /// Removes a resting order by id from whichever side it lives on.
+    /// Returns `true` if an order was found and removed.
+    pub fn cancel(&mut self, id: &str) -> bool {
+        for queue in self.bids.values_mut() {
+            if let Some(pos) = queue.iter().position(|o| o.id == id) {
+                queue.remove(pos);
+                self.bids.retain(|_, q| !q.is_empty());
+                return true;
+            }
+        }
+        for queue in self.asks.values_mut() {
+            if let Some(pos) = queue.iter().position(|o| o.id == id) {
+                queue.remove(pos);
+                self.asks.retain(|_, q| !q.is_empty());
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn best_bid(&self) -> Option<f64> {
+        self.bids.keys().next().map(|Reverse(k)| k.0)
+    }
+
+    pub fn best_ask(&self) -> Option<f64> {
+        self.asks.keys().next().map(|k| k.0)
+    }
+
+    /// Live bid-ask spread, or `None` while either side of the book is empty.
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()? - self.best_bid()?)
+    }
+
+    /// Evaluates `is_safe_entry` using this book's live spread instead of a
+    /// caller-supplied one.
+    pub fn check_entry(&self, current_price: f64, volatility: f64, max_spread: f64, max_volatility: f64) -> bool {
+        let spread = self.spread().unwrap_or(f64::INFINITY);
+        crate::is_safe_entry(current_price, spread, volatility, max_spread, max_volatility)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits() -> OrderLimits {
+        OrderLimits {
+            min_amount: 0.0,
+            max_amount: 1_000_000.0,
+            balance: 1_000_000.0,
+        }
+    }
+
+    fn order(id: &str, side: &str, price: f64, amount: f64) -> Order {
+        Order {
+            id: id.to_string(),
+            price,
+            amount,
+            side: side.to_string(),
+        }
+    }
+
+    #[test]
+    fn partial_fill_leaves_remainder_resting() {
+        let mut book = OrderBook::new();
+        book.submit(order("maker", "sell", 100.0, 5.0), &limits()).unwrap();
+
+        let trades = book.submit(order("taker", "buy", 100.0, 2.0), &limits()).unwrap();
+
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].amount, 2.0);
+        assert_eq!(book.best_ask(), Some(100.0));
+    }
+
+    #[test]
+    fn same_price_level_fills_in_arrival_order() {
+        let mut book = OrderBook::new();
+        book.submit(order("first", "sell", 100.0, 1.0), &limits()).unwrap();
+        book.submit(order("second", "sell", 100.0, 1.0), &limits()).unwrap();
+
+        let trades = book.submit(order("taker", "buy", 100.0, 2.0), &limits()).unwrap();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].maker_id, "first");
+        assert_eq!(trades[1].maker_id, "second");
+    }
+
+    #[test]
+    fn crosses_multiple_price_levels_best_price_first() {
+        let mut book = OrderBook::new();
+        book.submit(order("high", "sell", 101.0, 1.0), &limits()).unwrap();
+        book.submit(order("low", "sell", 100.0, 1.0), &limits()).unwrap();
+
+        let trades = book.submit(order("taker", "buy", 101.0, 2.0), &limits()).unwrap();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].maker_id, "low");
+        assert_eq!(trades[0].price, 100.0);
+        assert_eq!(trades[1].maker_id, "high");
+        assert_eq!(trades[1].price, 101.0);
+    }
+
+    #[test]
+    fn cancel_removes_resting_order_from_the_book() {
+        let mut book = OrderBook::new();
+        book.submit(order("maker", "buy", 100.0, 1.0), &limits()).unwrap();
+        assert_eq!(book.best_bid(), Some(100.0));
+
+        assert!(book.cancel("maker"));
+        assert_eq!(book.best_bid(), None);
+        assert!(!book.cancel("maker"));
+    }
+
+    #[test]
+    fn cancelled_order_does_not_fill() {
+        let mut book = OrderBook::new();
+        book.submit(order("maker", "buy", 100.0, 1.0), &limits()).unwrap();
+        book.cancel("maker");
+
+        let trades = book.submit(order("taker", "sell", 100.0, 1.0), &limits()).unwrap();
+
+        assert!(trades.is_empty());
+        assert_eq!(book.best_ask(), Some(100.0));
+    }
+
+    #[test]
+    fn invalid_order_is_rejected_without_touching_the_book() {
+        let mut book = OrderBook::new();
+
+        let result = book.submit(order("bad", "buy", -1.0, 1.0), &limits());
+
+        assert!(result.is_err());
+        assert_eq!(book.best_bid(), None);
+    }
+}
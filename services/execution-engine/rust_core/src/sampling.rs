@@ -0,0 +1,136 @@
+//! Seeded, statistically-correct random sampling for the simulation layer.
+//!
+//! A biased ad-hoc RNG would quietly corrupt every risk number downstream, so
+//! this module is the single place the rest of the crate draws randomness
+//! from. [`StandardNormal`] is used directly by the Monte Carlo engine;
+//! [`DiscreteGaussian`] is for integer lattice noise.
+
+/// Minimal splitmix64-style PRNG so native and WASM builds draw identical
+/// streams from the same seed without pulling in an external RNG crate here.
+struct Rng64 {
+    state: u64,
+}
+
+impl Rng64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform sample in `(0, 1]`, never zero so it's safe to feed into `ln`.
+    fn next_uniform(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 1.0) / ((1u64 << 53) as f64 + 1.0)
+    }
+}
+
+/// `N(0, 1)` sampler using the Box-Muller transform. Box-Muller produces two
+/// independent draws per pair of uniforms; the second is cached and handed
+/// back on the next call instead of being thrown away.
+pub struct StandardNormal {
+    rng: Rng64,
+    cached: Option<f64>,
+}
+
+impl StandardNormal {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Rng64::new(seed),
+            cached: None,
+        }
+    }
+
+    pub fn sample(&mut self) -> f64 {
+        if let Some(z1) = self.cached.take() {
+            return z1;
+        }
+
+        let u1 = self.rng.next_uniform();
+        let u2 = self.rng.next_uniform();
+        let r = (-2.0 * u1.ln()).sqrt();
+        let theta = std::f64::consts::TAU * u2;
+
+        let z0 = r * theta.cos();
+        let z1 = r * theta.sin();
+        self.cached = Some(z1);
+        z0
+    }
+}
+
+/// Discrete Gaussian sampler over `i64`, for integer lattice noise. Draws a
+/// candidate integer near `mu` and accepts it with probability proportional
+/// to `exp(-(x - mu)^2 / (2 * sigma^2))` (rejection sampling).
+pub struct DiscreteGaussian {
+    rng: Rng64,
+    mu: f64,
+    sigma: f64,
+}
+
+impl DiscreteGaussian {
+    pub fn new(seed: u64, mu: f64, sigma: f64) -> Self {
+        Self {
+            rng: Rng64::new(seed),
+            mu,
+            sigma,
+        }
+    }
+
+    pub fn sample(&mut self) -> i64 {
+        let tail = (6.0 * self.sigma).ceil() as i64 + 1;
+        loop {
+            let offset = (self.rng.next_uniform() * (2 * tail + 1) as f64).floor() as i64 - tail;
+            let candidate = self.mu.round() as i64 + offset;
+            let x = candidate as f64 - self.mu;
+            let acceptance = (-x * x / (2.0 * self.sigma * self.sigma)).exp();
+            if self.rng.next_uniform() <= acceptance {
+                return candidate;
+            }
+        }
+    }
+}
+
+/// Sanity check: draws `samples` discrete Gaussian values and confirms the
+/// empirical mean/variance stay within tolerance of `mu`/`sigma^2`. Guards
+/// against an obviously biased sampler slipping through review.
+pub fn dg_seems_okay(seed: u64, mu: f64, sigma: f64, samples: usize) -> bool {
+    let mut dg = DiscreteGaussian::new(seed, mu, sigma);
+    let draws: Vec<f64> = (0..samples).map(|_| dg.sample() as f64).collect();
+
+    let n = draws.len().max(1) as f64;
+    let sample_mean = draws.iter().sum::<f64>() / n;
+    let sample_var = draws.iter().map(|x| (x - sample_mean).powi(2)).sum::<f64>() / n;
+
+    let mean_ok = (sample_mean - mu).abs() < sigma.max(1.0);
+    let var_ok = (sample_var - sigma * sigma).abs() < (sigma * sigma).max(1.0) * 0.5;
+    mean_ok && var_ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discrete_gaussian_passes_its_own_sanity_check() {
+        assert!(dg_seems_okay(42, 0.0, 3.0, 5_000));
+        assert!(dg_seems_okay(1337, -10.0, 5.0, 5_000));
+    }
+
+    #[test]
+    fn standard_normal_mean_and_variance_are_close_to_zero_one() {
+        let mut normal = StandardNormal::new(7);
+        let draws: Vec<f64> = (0..10_000).map(|_| normal.sample()).collect();
+
+        let n = draws.len() as f64;
+        let mean = draws.iter().sum::<f64>() / n;
+        let variance = draws.iter().map(|z| (z - mean).powi(2)).sum::<f64>() / n;
+
+        assert!(mean.abs() < 0.05, "mean {} too far from 0", mean);
+        assert!((variance - 1.0).abs() < 0.1, "variance {} too far from 1", variance);
+    }
+}
@@ -0,0 +1,103 @@
+//! Monte Carlo Value-at-Risk engine.
+//!
+//! Simulates a geometric Brownian motion price path for a given spot price,
+//! drift and volatility, then reports empirical VaR and P&L statistics over
+//! the simulated terminal prices. Everything here is deterministic given a
+//! `seed`, so the WASM frontend and the native binary produce identical
+//! numbers for the same input.
+
+use serde::{Deserialize, Serialize};
+
+use crate::sampling::StandardNormal;
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SimulationInput {
+    /// Spot price.
+    #[serde(rename = "S0")]
+    pub s0: f64,
+    /// Annualized drift.
+    pub mu: f64,
+    /// Annualized volatility.
+    pub sigma: f64,
+    /// Simulation horizon, in days.
+    pub horizon: f64,
+    /// Number of simulated paths.
+    pub paths: usize,
+    /// Number of time steps per path (defaults to one per day).
+    #[serde(default)]
+    pub steps: Option<usize>,
+    /// RNG seed, for reproducible runs.
+    pub seed: u64,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct SimulationOutput {
+    pub paths: usize,
+    pub mean_pnl: f64,
+    pub std_pnl: f64,
+    pub var_95: f64,
+    pub var_99: f64,
+}
+
+/// Runs the Monte Carlo simulation described by `input` and returns VaR and
+/// P&L summary statistics over the simulated terminal prices. Rejects
+/// non-positive `S0`/`sigma`/`horizon` instead of letting them propagate
+/// into a `NaN` price path (e.g. `sqrt` of a negative `dt`).
+pub fn run_monte_carlo(input: &SimulationInput) -> Result<SimulationOutput, String> {
+    if input.s0 <= 0.0 {
+        return Err("S0 must be positive".to_string());
+    }
+    if input.sigma <= 0.0 {
+        return Err("sigma must be positive".to_string());
+    }
+    if input.horizon <= 0.0 {
+        return Err("horizon must be positive".to_string());
+    }
+
+    let steps = input.steps.unwrap_or(input.horizon.max(1.0) as usize).max(1);
+    let horizon_years = input.horizon / 365.0;
+    let dt = horizon_years / steps as f64;
+    let drift = (input.mu - 0.5 * input.sigma * input.sigma) * dt;
+    let vol = input.sigma * dt.sqrt();
+
+    let mut normal = StandardNormal::new(input.seed);
+    let mut pnl: Vec<f64> = Vec::with_capacity(input.paths);
+
+    for _ in 0..input.paths {
+        let mut price = input.s0;
+        for _ in 0..steps {
+            let z = normal.sample();
+            price *= (drift + vol * z).exp();
+        }
+        pnl.push(price - input.s0);
+    }
+
+    if pnl.is_empty() {
+        return Ok(SimulationOutput {
+            paths: 0,
+            mean_pnl: 0.0,
+            std_pnl: 0.0,
+            var_95: 0.0,
+            var_99: 0.0,
+        });
+    }
+
+    pnl.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n = pnl.len();
+    let mean_pnl = pnl.iter().sum::<f64>() / n as f64;
+    let variance = pnl.iter().map(|p| (p - mean_pnl).powi(2)).sum::<f64>() / n as f64;
+    let std_pnl = variance.sqrt();
+
+    let var_index = |q: f64| ((q * n as f64).floor() as usize).min(n - 1);
+    let var_95 = pnl[var_index(0.05)];
+    let var_99 = pnl[var_index(0.01)];
+
+    Ok(SimulationOutput {
+        paths: input.paths,
+        mean_pnl,
+        std_pnl,
+        var_95,
+        var_99,
+    })
+}